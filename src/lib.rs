@@ -0,0 +1,6 @@
+pub mod spsc;
+
+#[cfg(feature = "pipe")]
+pub mod pipe;
+
+pub use spsc::{Consumer, Producer, Spsc};
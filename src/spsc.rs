@@ -1,18 +1,100 @@
 use std::alloc::{alloc, dealloc, Layout};
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::{marker::PhantomData, mem, ptr};
+use std::cell::Cell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread;
+use std::{marker::PhantomData, mem, ptr, slice};
+
+use atomic_waker::AtomicWaker;
+use crossbeam_utils::Backoff;
 
 const CACHE_LINE_SIZE: usize = 64;
 
+// Adapts a parked thread to a `Waker` so `read_blocking`/`write_blocking`
+// can share the same `AtomicWaker` slot that the async `poll_read` path
+// registers into. `pub(crate)` so `pipe`'s tests can drive its async I/O
+// synchronously the same way, instead of keeping their own copy.
+pub(crate) struct ThreadWaker(pub(crate) thread::Thread);
+
+impl Wake for ThreadWaker {
+  fn wake(self: Arc<Self>) {
+    self.0.unpark();
+  }
+
+  fn wake_by_ref(self: &Arc<Self>) {
+    self.0.unpark();
+  }
+}
+
+// Split into producer-written and consumer-written cache lines, same as
+// `read_index`/`write_index` below. Compiled out when the feature is off.
+#[cfg(feature = "stats")]
+#[repr(C)]
+struct Stats {
+  pad0: [u8; CACHE_LINE_SIZE],
+  writes_ok: AtomicUsize,
+  writes_full: AtomicUsize,
+  high_water_mark: AtomicUsize,
+  pad1: [u8; CACHE_LINE_SIZE - 3 * mem::size_of::<AtomicUsize>()],
+  reads_ok: AtomicUsize,
+  pad2: [u8; CACHE_LINE_SIZE - mem::size_of::<AtomicUsize>()],
+}
+
+#[cfg(feature = "stats")]
+impl Stats {
+  fn new() -> Self {
+    Stats {
+      pad0: [0; CACHE_LINE_SIZE],
+      writes_ok: AtomicUsize::new(0),
+      writes_full: AtomicUsize::new(0),
+      high_water_mark: AtomicUsize::new(0),
+      pad1: [0; CACHE_LINE_SIZE - 3 * mem::size_of::<AtomicUsize>()],
+      reads_ok: AtomicUsize::new(0),
+      pad2: [0; CACHE_LINE_SIZE - mem::size_of::<AtomicUsize>()],
+    }
+  }
+}
+
+// Snapshot returned by `Spsc::stats`. Only present with the `stats` feature.
+#[cfg(feature = "stats")]
+#[derive(Debug, Clone, Copy)]
+pub struct SpscStats {
+  pub writes_ok: usize,
+  pub writes_full: usize,
+  pub reads_ok: usize,
+  pub high_water_mark: usize,
+}
+
 #[repr(C)]
 pub struct Spsc<T> {
   pad0: [u8; CACHE_LINE_SIZE],
   size: usize,
+  mask: usize,
   records: *mut T,
+  // Grow monotonically rather than wrapping at `size`; the physical slot
+  // is `index & mask`. This removes the one-slot full/empty sentinel a
+  // wrapped index would need, so `capacity()` is the full `size` instead
+  // of `size - 1`, and `size_guess` is a branchless subtraction.
   read_index: AtomicUsize,
-  pad1: [u8; CACHE_LINE_SIZE - mem::size_of::<AtomicUsize>()],
+  // Trails `read_index`: bumped only after a slot's `ptr::read`/
+  // `drop_in_place` actually runs, so `force_push` can tell a slot freed
+  // up by a concurrent `read`/`pop_front` is truly vacated before reusing
+  // it, rather than racing that still-in-flight access.
+  read_commit: AtomicUsize,
+  pad1: [u8; CACHE_LINE_SIZE - 2 * mem::size_of::<AtomicUsize>()],
   write_index: AtomicUsize,
   pad2: [u8; CACHE_LINE_SIZE - mem::size_of::<AtomicUsize>()],
+  closed: AtomicBool,
+  // Set (never cleared) the first time `force_push` runs, so the slice
+  // API below can assert it's not being combined with the eviction path
+  // it wasn't built to race against.
+  force_push_used: AtomicBool,
+  read_waker: AtomicWaker,
+  write_waker: AtomicWaker,
+  #[cfg(feature = "stats")]
+  stats: Stats,
   _marker: PhantomData<T>,
 }
 
@@ -20,8 +102,11 @@ unsafe impl<T: Send> Send for Spsc<T> {}
 unsafe impl<T: Send> Sync for Spsc<T> {}
 
 impl<T> Spsc<T> {
+  // Rounds `size` up to the next power of two so every `& mask` below is
+  // exact; `mask = size - 1` then replaces the modulo on the hot path.
   pub fn new(size: usize) -> Self {
     assert!(size >= 2, "size must be >= 2");
+    let size = size.next_power_of_two();
     let layout = Layout::array::<T>(size).expect("invalid layout");
     let records = unsafe { alloc(layout) as *mut T };
     if records.is_null() {
@@ -30,78 +115,407 @@ impl<T> Spsc<T> {
     Spsc {
       pad0: [0; CACHE_LINE_SIZE],
       size,
+      mask: size - 1,
       records,
       read_index: AtomicUsize::new(0),
-      pad1: [0; CACHE_LINE_SIZE - mem::size_of::<AtomicUsize>()],
+      read_commit: AtomicUsize::new(0),
+      pad1: [0; CACHE_LINE_SIZE - 2 * mem::size_of::<AtomicUsize>()],
       write_index: AtomicUsize::new(0),
       pad2: [0; CACHE_LINE_SIZE - mem::size_of::<AtomicUsize>()],
+      closed: AtomicBool::new(false),
+      force_push_used: AtomicBool::new(false),
+      read_waker: AtomicWaker::new(),
+      write_waker: AtomicWaker::new(),
+      #[cfg(feature = "stats")]
+      stats: Stats::new(),
       _marker: PhantomData,
     }
   }
 
+  #[cfg(feature = "stats")]
+  fn record_write_ok(&self, size_guess: usize) {
+    self.stats.writes_ok.fetch_add(1, Ordering::Relaxed);
+    self.stats.high_water_mark.fetch_max(size_guess, Ordering::Relaxed);
+  }
+
+  #[cfg(not(feature = "stats"))]
+  fn record_write_ok(&self, _size_guess: usize) {}
+
+  #[cfg(feature = "stats")]
+  fn record_write_full(&self) {
+    self.stats.writes_full.fetch_add(1, Ordering::Relaxed);
+  }
+
+  #[cfg(not(feature = "stats"))]
+  fn record_write_full(&self) {}
+
+  #[cfg(feature = "stats")]
+  fn record_read_ok(&self) {
+    self.stats.reads_ok.fetch_add(1, Ordering::Relaxed);
+  }
+
+  #[cfg(not(feature = "stats"))]
+  fn record_read_ok(&self) {}
+
+  // Snapshot of the counters accumulated so far. Only present with the
+  // `stats` feature.
+  #[cfg(feature = "stats")]
+  pub fn stats(&self) -> SpscStats {
+    SpscStats {
+      writes_ok: self.stats.writes_ok.load(Ordering::Relaxed),
+      writes_full: self.stats.writes_full.load(Ordering::Relaxed),
+      reads_ok: self.stats.reads_ok.load(Ordering::Relaxed),
+      high_water_mark: self.stats.high_water_mark.load(Ordering::Relaxed),
+    }
+  }
+
+  // Splits the queue into a single-writer `Producer` and single-reader
+  // `Consumer`, enforcing the single-producer/single-consumer contract in
+  // the type system instead of leaving it as a convention on `&Spsc<T>`.
+  pub fn split(self) -> (Producer<T>, Consumer<T>) {
+    let queue = Arc::new(self);
+    (
+      Producer {
+        queue: Arc::clone(&queue),
+        _not_sync: PhantomData,
+      },
+      Consumer {
+        queue,
+        _not_sync: PhantomData,
+      },
+    )
+  }
+
   pub fn write(&self, record: T) -> bool {
     let current_write = self.write_index.load(Ordering::Relaxed);
-    let next_record = (current_write + 1) % self.size;
-    if next_record != self.read_index.load(Ordering::Acquire) {
-      unsafe {
-        ptr::write(self.records.add(current_write), record);
+    let current_read = self.read_index.load(Ordering::Acquire);
+    if current_write - current_read == self.size {
+      self.record_write_full();
+      return false;
+    }
+    unsafe {
+      ptr::write(self.records.add(current_write & self.mask), record);
+    }
+    self.write_index.store(current_write + 1, Ordering::Release);
+    if current_write == current_read {
+      // Was empty before this write: wake a blocked/parked reader.
+      self.read_waker.wake();
+    }
+    self.record_write_ok(current_write + 1 - current_read);
+    true
+  }
+
+  // Spins briefly with `Backoff`, then parks the thread until a write
+  // wakes it. Distinguishes "no data" from "queue closed" the same way
+  // `read()` does: both report it as `None` after a wakeup.
+  pub fn read_blocking(&self) -> Option<T> {
+    let backoff = Backoff::new();
+    while !backoff.is_completed() {
+      if let Some(record) = self.read() {
+        return Some(record);
       }
-      self.write_index.store(next_record, Ordering::Release);
-      true
-    } else {
-      false
+      backoff.snooze();
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    loop {
+      self.read_waker.register(&waker);
+      if let Some(record) = self.read() {
+        return Some(record);
+      }
+      thread::park();
     }
   }
 
-  // todo optimize
-  pub fn write_all(&self, records: Vec<T>) {
-    for i in records {
-      self.write(i);
+  // Async counterpart of `read_blocking`: registers `cx`'s waker and
+  // re-checks before returning `Pending`, so a write racing with this
+  // poll is never missed.
+  pub fn poll_read(&self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+    if let Some(record) = self.read() {
+      return Poll::Ready(Some(record));
+    }
+    self.read_waker.register(cx.waker());
+    match self.read() {
+      Some(record) => Poll::Ready(Some(record)),
+      None => Poll::Pending,
+    }
+  }
+
+  // Raw waker access for callers (currently just `pipe`) that drive
+  // `read_index`/`write_index` themselves via the slice API instead of
+  // `write`/`read`, so they can still piggyback on these wakers instead
+  // of keeping their own.
+  #[cfg(feature = "pipe")]
+  pub(crate) fn register_read_waker(&self, waker: &Waker) {
+    self.read_waker.register(waker);
+  }
+
+  #[cfg(feature = "pipe")]
+  pub(crate) fn register_write_waker(&self, waker: &Waker) {
+    self.write_waker.register(waker);
+  }
+
+  #[cfg(feature = "pipe")]
+  pub(crate) fn wake_read(&self) {
+    self.read_waker.wake();
+  }
+
+  #[cfg(feature = "pipe")]
+  pub(crate) fn wake_write(&self) {
+    self.write_waker.wake();
+  }
+
+  // Blocking counterpart of `write`: spins, then parks until space opens
+  // up, rather than dropping `record` when the ring is full.
+  pub fn write_blocking(&self, record: T) {
+    let backoff = Backoff::new();
+    let mut record = Some(record);
+    let mut try_write = || {
+      if self.is_full() {
+        false
+      } else {
+        let pushed = self.write(record.take().expect("write_blocking: record taken exactly once"));
+        debug_assert!(pushed, "producer is the sole writer, so space cannot vanish");
+        true
+      }
+    };
+
+    while !backoff.is_completed() {
+      if try_write() {
+        return;
+      }
+      backoff.snooze();
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    loop {
+      self.write_waker.register(&waker);
+      if try_write() {
+        return;
+      }
+      thread::park();
+    }
+  }
+
+  // When full, evicts and returns the oldest record instead of dropping
+  // the new one.
+  // Writes `record` into the next free slot and wakes a blocked/parked
+  // reader on the empty-to-non-empty transition, same as `write`'s success
+  // path; shared by force_push's two non-eviction branches.
+  fn insert_free_slot(&self, current_write: usize, current_read: usize, record: T) {
+    unsafe {
+      ptr::write(self.records.add(current_write & self.mask), record);
+    }
+    self.write_index.store(current_write + 1, Ordering::Release);
+    if current_write == current_read {
+      self.read_waker.wake();
+    }
+    self.record_write_ok(current_write + 1 - current_read);
+  }
+
+  pub fn force_push(&self, record: T) -> Option<T> {
+    self.force_push_used.store(true, Ordering::Relaxed);
+    let current_write = self.write_index.load(Ordering::Relaxed);
+    let mut current_read = self.read_index.load(Ordering::Acquire);
+
+    if current_write - current_read != self.size {
+      self.insert_free_slot(current_write, current_read, record);
+      return None;
+    }
+
+    loop {
+      let next_read = current_read + 1;
+      match self.read_index.compare_exchange(
+        current_read,
+        next_read,
+        Ordering::AcqRel,
+        Ordering::Acquire,
+      ) {
+        Ok(_) => {
+          let evicted = unsafe { ptr::read(self.records.add(current_read & self.mask)) };
+          unsafe {
+            ptr::write(self.records.add(current_write & self.mask), record);
+          }
+          self.write_index.store(current_write + 1, Ordering::Release);
+          // The write always succeeds here (eviction, not failure), so
+          // only record_write_ok, same as write()'s success case; an
+          // eviction isn't a Consumer::read, so it doesn't touch reads_ok.
+          self.record_write_ok(self.size);
+          return Some(evicted);
+        }
+        Err(actual_read) => {
+          current_read = actual_read;
+          if current_write - current_read != self.size {
+            // The consumer raced ahead and freed a slot on its own; no
+            // eviction needed after all. Its CAS above published the new
+            // `read_index` before its own `ptr::read`/`drop_in_place` of
+            // that slot ran, so wait for `read_commit` to catch up first
+            // — the slot we're about to reuse is the exact one it just
+            // vacated, and writing into it any earlier would race that
+            // still-in-flight read.
+            let backoff = Backoff::new();
+            while self.read_commit.load(Ordering::Acquire) < current_read {
+              backoff.spin();
+            }
+            self.insert_free_slot(current_write, current_read, record);
+            return None;
+          }
+        }
+      }
     }
   }
 
+  // `read_index` is also advanced by `force_push`'s eviction path, so this
+  // claims a slot via CAS before reading it rather than reading first and
+  // storing after: two racing claims of the same slot must not both win.
   pub fn read(&self) -> Option<T> {
-    let current_read = self.read_index.load(Ordering::Relaxed);
-    if current_read == self.write_index.load(Ordering::Acquire) {
-      None
-    } else {
-      let next_record = (current_read + 1) % self.size;
-      let record = unsafe { ptr::read(self.records.add(current_read)) };
-      self.read_index.store(next_record, Ordering::Release);
-      Some(record)
+    let mut current_read = self.read_index.load(Ordering::Relaxed);
+    loop {
+      let current_write = self.write_index.load(Ordering::Acquire);
+      if current_read == current_write {
+        return None;
+      }
+      let was_full = current_write - current_read == self.size;
+      match self.read_index.compare_exchange(
+        current_read,
+        current_read + 1,
+        Ordering::AcqRel,
+        Ordering::Acquire,
+      ) {
+        Ok(_) => {
+          let record = unsafe { ptr::read(self.records.add(current_read & self.mask)) };
+          // Publish that this slot's read has actually happened, not just
+          // that read_index claimed it, so force_push's fallback path
+          // knows it's safe to reuse.
+          self.read_commit.store(current_read + 1, Ordering::Release);
+          if was_full {
+            // Was full before this read: wake a blocked/parked writer.
+            self.write_waker.wake();
+          }
+          self.record_read_ok();
+          return Some(record);
+        }
+        Err(actual_read) => current_read = actual_read,
+      }
     }
   }
 
-  // todo optimize
-  pub fn read_all(&self) -> Vec<T> {
-    let mut frames = Vec::with_capacity(32);
-    while let Some(frame) = self.read() {
-      frames.push(frame);
+  // Filled span `[read_index, write_index)` as up to two contiguous slices,
+  // split at the wrap point, mirroring `VecDeque::as_slices`.
+  //
+  // Unlike `read`/`pop_front`, this doesn't claim `read_index` via CAS, so
+  // it must not be combined with `force_push` on the same queue: a
+  // concurrent eviction can `ptr::write` into the exact slot this hands
+  // out a `&T` into. Use `read`/`pop_front` instead if the producer ever
+  // calls `force_push`.
+  pub fn readable_slices(&self) -> (&[T], &[T]) {
+    debug_assert!(
+      !self.force_push_used.load(Ordering::Relaxed),
+      "readable_slices/advance_read/read_all race force_push's read_index CAS; use read()/pop_front() instead"
+    );
+    let read_index = self.read_index.load(Ordering::Acquire);
+    let write_index = self.write_index.load(Ordering::Acquire);
+    let filled = write_index - read_index;
+    let start = read_index & self.mask;
+    let first_len = filled.min(self.size - start);
+    let first = unsafe { slice::from_raw_parts(self.records.add(start), first_len) };
+    let second = unsafe { slice::from_raw_parts(self.records, filled - first_len) };
+    (first, second)
+  }
+
+  // Free span as up to two contiguous slices of uninitialized memory,
+  // split at the wrap point. Takes `&self`, not `&mut self`, like the rest
+  // of this type: callers must uphold the single-producer contract
+  // themselves (enforced for them via `Producer` once split).
+  #[allow(clippy::mut_from_ref)]
+  pub fn writable_slices(&self) -> (&mut [MaybeUninit<T>], &mut [MaybeUninit<T>]) {
+    let read_index = self.read_index.load(Ordering::Acquire);
+    let write_index = self.write_index.load(Ordering::Acquire);
+    let free = self.size - (write_index - read_index);
+    let start = write_index & self.mask;
+    let first_len = free.min(self.size - start);
+    let records = self.records as *mut MaybeUninit<T>;
+    let first = unsafe { slice::from_raw_parts_mut(records.add(start), first_len) };
+    let second = unsafe { slice::from_raw_parts_mut(records, free - first_len) };
+    (first, second)
+  }
+
+  // Commits `n` elements written via `writable_slices` by advancing the
+  // write index; must not exceed the slices' combined length. Wakes a
+  // blocked/parked reader on the empty-to-non-empty transition, same as
+  // `write`, so bulk writers don't need their own wakers.
+  pub fn advance_write(&self, n: usize) {
+    if n == 0 {
+      return;
+    }
+    let write_index = self.write_index.load(Ordering::Relaxed);
+    let read_index = self.read_index.load(Ordering::Acquire);
+    self.write_index.store(write_index + n, Ordering::Release);
+    if write_index == read_index {
+      self.read_waker.wake();
+    }
+  }
+
+  // Commits `n` elements consumed via `readable_slices` by advancing the
+  // read index; must not exceed the slices' combined length. Wakes a
+  // blocked/parked writer on the full-to-non-full transition, same as
+  // `read`. Same force_push caveat as `readable_slices`.
+  pub fn advance_read(&self, n: usize) {
+    debug_assert!(
+      !self.force_push_used.load(Ordering::Relaxed),
+      "readable_slices/advance_read/read_all race force_push's read_index CAS; use read()/pop_front() instead"
+    );
+    if n == 0 {
+      return;
+    }
+    let read_index = self.read_index.load(Ordering::Relaxed);
+    let write_index = self.write_index.load(Ordering::Acquire);
+    let was_full = write_index - read_index == self.size;
+    self.read_index.store(read_index + n, Ordering::Release);
+    if was_full {
+      self.write_waker.wake();
     }
-    frames
   }
 
+  // Takes `&self` like `writable_slices`: the single-consumer contract is
+  // the caller's responsibility here, not the type system's.
+  #[allow(clippy::mut_from_ref)]
   pub fn front_ptr(&self) -> Option<&mut T> {
     let current_read = self.read_index.load(Ordering::Relaxed);
     if current_read == self.write_index.load(Ordering::Acquire) {
       None
     } else {
-      unsafe { Some(&mut *self.records.add(current_read)) }
+      unsafe { Some(&mut *self.records.add(current_read & self.mask)) }
     }
   }
 
+  // Same CAS-before-drop reasoning as `read`: `force_push` may also be
+  // advancing `read_index` concurrently.
   pub fn pop_front(&self) {
-    let current_read = self.read_index.load(Ordering::Relaxed);
-    assert_ne!(
-      current_read,
-      self.write_index.load(Ordering::Acquire),
-      "queue must not be empty"
-    );
-    let next_record = (current_read + 1) % self.size;
-    unsafe {
-      ptr::drop_in_place(self.records.add(current_read));
+    let mut current_read = self.read_index.load(Ordering::Relaxed);
+    loop {
+      assert_ne!(
+        current_read,
+        self.write_index.load(Ordering::Acquire),
+        "queue must not be empty"
+      );
+      match self.read_index.compare_exchange(
+        current_read,
+        current_read + 1,
+        Ordering::AcqRel,
+        Ordering::Acquire,
+      ) {
+        Ok(_) => {
+          unsafe {
+            ptr::drop_in_place(self.records.add(current_read & self.mask));
+          }
+          // Same publish as `read`: force_push's fallback waits on this.
+          self.read_commit.store(current_read + 1, Ordering::Release);
+          return;
+        }
+        Err(actual_read) => current_read = actual_read,
+      }
     }
-    self.read_index.store(next_record, Ordering::Release);
   }
 
   pub fn is_empty(&self) -> bool {
@@ -109,22 +523,48 @@ impl<T> Spsc<T> {
   }
 
   pub fn is_full(&self) -> bool {
-    let next_record = (self.write_index.load(Ordering::Acquire) + 1) % self.size;
-    next_record == self.read_index.load(Ordering::Acquire)
+    let write_index = self.write_index.load(Ordering::Acquire);
+    let read_index = self.read_index.load(Ordering::Acquire);
+    write_index - read_index == self.size
   }
 
   pub fn size_guess(&self) -> usize {
     let read_index = self.read_index.load(Ordering::Acquire);
     let write_index = self.write_index.load(Ordering::Acquire);
-    if write_index >= read_index {
-      write_index - read_index
-    } else {
-      self.size - read_index + write_index
-    }
+    write_index - read_index
   }
 
   pub fn capacity(&self) -> usize {
-    self.size - 1
+    self.size
+  }
+}
+
+impl<T: Copy> Spsc<T> {
+  // Bulk write via `writable_slices`, copying in at most two `memcpy`s
+  // instead of one atomic store per element. Elements past the free
+  // space are silently dropped, same as a full `write`.
+  pub fn write_all(&self, records: Vec<T>) {
+    let (first, second) = self.writable_slices();
+    let n1 = first.len().min(records.len());
+    unsafe {
+      ptr::copy_nonoverlapping(records.as_ptr(), first.as_mut_ptr() as *mut T, n1);
+    }
+    let remaining = &records[n1..];
+    let n2 = second.len().min(remaining.len());
+    unsafe {
+      ptr::copy_nonoverlapping(remaining.as_ptr(), second.as_mut_ptr() as *mut T, n2);
+    }
+    self.advance_write(n1 + n2);
+  }
+
+  // Bulk read via `readable_slices`, copying out in at most two `memcpy`s.
+  pub fn read_all(&self) -> Vec<T> {
+    let (first, second) = self.readable_slices();
+    let mut frames = Vec::with_capacity(first.len() + second.len());
+    frames.extend_from_slice(first);
+    frames.extend_from_slice(second);
+    self.advance_read(frames.len());
+    frames
   }
 }
 
@@ -138,9 +578,9 @@ impl<T> Drop for Spsc<T> {
     let end_index = self.write_index.load(Ordering::Relaxed);
     while read_index != end_index {
       unsafe {
-        ptr::drop_in_place(self.records.add(read_index));
+        ptr::drop_in_place(self.records.add(read_index & self.mask));
       }
-      read_index = (read_index + 1) % self.size;
+      read_index += 1;
     }
 
     unsafe {
@@ -149,3 +589,278 @@ impl<T> Drop for Spsc<T> {
     }
   }
 }
+
+// The write half of a split `Spsc`. `PhantomData<Cell<()>>` makes this
+// `Send` but `!Sync`, so a `Producer` can move to a thread but never be
+// shared by reference, matching the single-producer contract.
+pub struct Producer<T> {
+  queue: Arc<Spsc<T>>,
+  _not_sync: PhantomData<Cell<()>>,
+}
+
+impl<T> Producer<T> {
+  pub fn write(&self, record: T) -> bool {
+    self.queue.write(record)
+  }
+
+  pub fn write_blocking(&self, record: T) {
+    self.queue.write_blocking(record)
+  }
+
+  pub fn force_push(&self, record: T) -> Option<T> {
+    self.queue.force_push(record)
+  }
+
+  #[allow(clippy::mut_from_ref)]
+  pub fn writable_slices(&self) -> (&mut [MaybeUninit<T>], &mut [MaybeUninit<T>]) {
+    self.queue.writable_slices()
+  }
+
+  pub fn advance_write(&self, n: usize) {
+    self.queue.advance_write(n)
+  }
+
+  pub fn is_full(&self) -> bool {
+    self.queue.is_full()
+  }
+
+  #[cfg(feature = "stats")]
+  pub fn stats(&self) -> SpscStats {
+    self.queue.stats()
+  }
+}
+
+impl<T: Copy> Producer<T> {
+  pub fn write_all(&self, records: Vec<T>) {
+    self.queue.write_all(records)
+  }
+}
+
+impl<T> Drop for Producer<T> {
+  fn drop(&mut self) {
+    self.queue.closed.store(true, Ordering::Release);
+  }
+}
+
+// The read half of a split `Spsc`. Like `Producer`, `Send` but `!Sync`.
+pub struct Consumer<T> {
+  queue: Arc<Spsc<T>>,
+  _not_sync: PhantomData<Cell<()>>,
+}
+
+impl<T: Copy> Consumer<T> {
+  pub fn read_all(&self) -> Vec<T> {
+    self.queue.read_all()
+  }
+}
+
+impl<T> Consumer<T> {
+  pub fn read(&self) -> Option<T> {
+    self.queue.read()
+  }
+
+  pub fn read_blocking(&self) -> Option<T> {
+    self.queue.read_blocking()
+  }
+
+  pub fn poll_read(&self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+    self.queue.poll_read(cx)
+  }
+
+  #[allow(clippy::mut_from_ref)]
+  pub fn front_ptr(&self) -> Option<&mut T> {
+    self.queue.front_ptr()
+  }
+
+  pub fn pop_front(&self) {
+    self.queue.pop_front()
+  }
+
+  pub fn readable_slices(&self) -> (&[T], &[T]) {
+    self.queue.readable_slices()
+  }
+
+  pub fn advance_read(&self, n: usize) {
+    self.queue.advance_read(n)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.queue.is_empty()
+  }
+
+  // Distinguishes "empty for now" from "empty forever": once the
+  // `Producer` has been dropped, no more data can ever arrive.
+  pub fn is_closed(&self) -> bool {
+    self.queue.closed.load(Ordering::Acquire)
+  }
+
+  #[cfg(feature = "stats")]
+  pub fn stats(&self) -> SpscStats {
+    self.queue.stats()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Races `force_push` against `read` on a full ring: both sides can claim
+  // `read_index` for the same slot, and a double `ptr::read` there would
+  // drop the evicted/read value twice.
+  #[test]
+  fn force_push_races_read_without_double_free() {
+    let ring = Arc::new(Spsc::<Arc<()>>::new(4));
+    for _ in 0..4 {
+      assert!(ring.write(Arc::new(())));
+    }
+
+    let producer = Arc::clone(&ring);
+    let pusher = thread::spawn(move || {
+      for _ in 0..100_000 {
+        producer.force_push(Arc::new(()));
+      }
+    });
+
+    let consumer = Arc::clone(&ring);
+    let reader = thread::spawn(move || {
+      for _ in 0..100_000 {
+        consumer.read();
+      }
+    });
+
+    pusher.join().unwrap();
+    reader.join().unwrap();
+
+    while ring.read().is_some() {}
+  }
+
+  #[test]
+  fn readable_writable_slices_round_trip() {
+    let ring = Spsc::<u32>::new(4);
+    let (first, second) = ring.writable_slices();
+    for (i, slot) in first.iter_mut().chain(second.iter_mut()).enumerate() {
+      slot.write(i as u32);
+    }
+    ring.advance_write(4);
+
+    let (first, second) = ring.readable_slices();
+    let read: Vec<u32> = first.iter().chain(second.iter()).copied().collect();
+    assert_eq!(read, vec![0, 1, 2, 3]);
+    ring.advance_read(4);
+    assert!(ring.is_empty());
+  }
+
+  #[test]
+  fn slices_split_at_wrap_boundary() {
+    let ring = Spsc::<u32>::new(4);
+    for i in 0..3 {
+      assert!(ring.write(i));
+    }
+    assert_eq!(ring.read(), Some(0));
+    assert_eq!(ring.read(), Some(1));
+    assert!(ring.write(10));
+    assert!(ring.write(11));
+
+    let (first, second) = ring.readable_slices();
+    assert_eq!(first, &[2, 10]);
+    assert_eq!(second, &[11]);
+  }
+
+  #[test]
+  #[should_panic(expected = "force_push")]
+  fn readable_slices_after_force_push_panics_in_debug() {
+    let ring = Spsc::<u32>::new(4);
+    ring.force_push(1);
+    let _ = ring.readable_slices();
+  }
+
+  // Pushes far more records than the ring's capacity through write_blocking/
+  // read_blocking across real threads, so both sides must actually park and
+  // get woken rather than just spin past an always-ready queue.
+  #[test]
+  fn blocking_write_read_round_trip_across_threads() {
+    let ring = Arc::new(Spsc::<u32>::new(4));
+
+    let producer = Arc::clone(&ring);
+    let writer = thread::spawn(move || {
+      for i in 0..10_000 {
+        producer.write_blocking(i);
+      }
+    });
+
+    let consumer = Arc::clone(&ring);
+    let reader = thread::spawn(move || {
+      let mut received = Vec::with_capacity(10_000);
+      for _ in 0..10_000 {
+        received.push(consumer.read_blocking().expect("writer hasn't closed yet"));
+      }
+      received
+    });
+
+    writer.join().unwrap();
+    let received = reader.join().unwrap();
+    assert_eq!(received, (0..10_000).collect::<Vec<_>>());
+  }
+
+  // Same as above but through poll_read, so a `Waker` registered on one
+  // thread must actually be woken by a write on another rather than the
+  // reader having to spin.
+  #[test]
+  #[cfg(feature = "stats")]
+  fn stats_count_writes_reads_and_high_water_mark() {
+    let ring = Spsc::<u32>::new(4);
+    for i in 0..4 {
+      assert!(ring.write(i));
+    }
+    assert!(!ring.write(4));
+    assert_eq!(ring.read(), Some(0));
+    assert_eq!(ring.force_push(5), None);
+    assert_eq!(ring.force_push(6), Some(1));
+
+    let stats = ring.stats();
+    assert_eq!(stats.writes_ok, 6);
+    assert_eq!(stats.writes_full, 1);
+    assert_eq!(stats.reads_ok, 1);
+    assert_eq!(stats.high_water_mark, 4);
+  }
+
+  #[test]
+  fn new_rounds_capacity_up_to_power_of_two() {
+    assert_eq!(Spsc::<u32>::new(2).capacity(), 2);
+    assert_eq!(Spsc::<u32>::new(3).capacity(), 4);
+    assert_eq!(Spsc::<u32>::new(4).capacity(), 4);
+    assert_eq!(Spsc::<u32>::new(5).capacity(), 8);
+    assert_eq!(Spsc::<u32>::new(100).capacity(), 128);
+  }
+
+  #[test]
+  fn poll_read_wakes_across_threads() {
+    let ring = Arc::new(Spsc::<u32>::new(4));
+
+    let producer = Arc::clone(&ring);
+    let writer = thread::spawn(move || {
+      for i in 0..10_000 {
+        producer.write_blocking(i);
+      }
+    });
+
+    let consumer = Arc::clone(&ring);
+    let reader = thread::spawn(move || {
+      let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+      let mut cx = Context::from_waker(&waker);
+      let mut received = Vec::with_capacity(10_000);
+      while received.len() < 10_000 {
+        match consumer.poll_read(&mut cx) {
+          Poll::Ready(Some(record)) => received.push(record),
+          Poll::Ready(None) => unreachable!("writer hasn't closed yet"),
+          Poll::Pending => thread::park(),
+        }
+      }
+      received
+    });
+
+    writer.join().unwrap();
+    let received = reader.join().unwrap();
+    assert_eq!(received, (0..10_000).collect::<Vec<_>>());
+  }
+}